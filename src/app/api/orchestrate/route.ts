@@ -1,5 +1,6 @@
 // src/app/api/orchestrate/route.ts
 import { NextRequest, NextResponse } from 'next/server';
+import { z } from 'zod';
 import { createClient } from '@/lib/supabase/server';
 import { AVAILABLE_TOOLS, convertToolsForOpenAI } from '@/lib/tools/function-tools';
 
@@ -11,10 +12,49 @@ const ORCHESTRATION_STRATEGIES = {
   CONSENSUS: 'consensus'    // Multiple agents vote/agree
 };
 
+const MAX_PARALLEL_AGENTS = 10;
+const MAX_PROMPT_LENGTH = 20000;
+
+const orchestrateRequestSchema = z.object({
+  prompt: z.string()
+    .max(MAX_PROMPT_LENGTH, `Must be at most ${MAX_PROMPT_LENGTH} characters`)
+    .refine(s => s.trim().length > 0, 'Must not be empty'),
+  pattern: z.enum(['simple', 'single', 'parallel', 'feedback', 'structured']),
+  systemPrompt: z.string().max(MAX_PROMPT_LENGTH, `Must be at most ${MAX_PROMPT_LENGTH} characters`).optional(),
+  parallelAgents: z.number()
+    .int('Must be an integer')
+    .min(1, 'Must be at least 1')
+    .max(MAX_PARALLEL_AGENTS, `Must be at most ${MAX_PARALLEL_AGENTS}`)
+    .default(1),
+  strategy: z.enum(['power', 'decompose', 'perspectives', 'consensus']).default('decompose'),
+  tools: z.array(z.string()).optional(),
+  conversationHistory: z.array(z.any()).optional(),
+});
+
 export async function POST(request: NextRequest) {
   try {
-    const body = await request.json();
-    const { prompt, pattern, systemPrompt, parallelAgents, tools, conversationHistory, strategy = 'decompose' } = body;
+    let body;
+    try {
+      body = await request.json();
+    } catch {
+      return NextResponse.json(
+        { error: 'Request body must be valid JSON' },
+        { status: 400 }
+      );
+    }
+
+    const parsed = orchestrateRequestSchema.safeParse(body);
+    if (!parsed.success) {
+      return NextResponse.json(
+        {
+          error: 'Invalid orchestration request',
+          fieldErrors: parsed.error.flatten().fieldErrors
+        },
+        { status: 422 }
+      );
+    }
+
+    const { prompt, pattern, systemPrompt, parallelAgents, tools, conversationHistory, strategy } = parsed.data;
 
     const apiKey = process.env.OPENAI_API_KEY;
     if (!apiKey) {
@@ -1,37 +1,66 @@
 import { NextRequest, NextResponse } from 'next/server';
+import { z } from 'zod';
 import { searchDocumentTool, searchSingleWordTool } from '@/lib/tools/document-search-tools';
 
+const MAX_TERM_LENGTH = 200;
+const MAX_SEARCH_TERMS = 100;
+
+const searchTerm = z.string()
+  .max(MAX_TERM_LENGTH, `Must be at most ${MAX_TERM_LENGTH} characters`)
+  .refine(s => s.trim().length > 0, 'Must not be empty');
+
+const searchRequestSchema = z.discriminatedUnion('type', [
+  searchSingleWordTool.parameters.extend({
+    type: z.literal('single-word'),
+    word: searchTerm,
+  }),
+  searchDocumentTool.parameters.extend({
+    type: z.literal('multiple-words'),
+    searchTerms: z.array(searchTerm)
+      .min(1, 'At least one search term is required')
+      .max(MAX_SEARCH_TERMS, `At most ${MAX_SEARCH_TERMS} search terms are allowed`),
+  }),
+]);
+
 export async function POST(request: NextRequest) {
   try {
-    const body = await request.json();
-    const { type, documentId, searchTerms, word, caseSensitive = false } = body;
-
-    if (!documentId) {
+    let body;
+    try {
+      body = await request.json();
+    } catch {
       return NextResponse.json(
-        { error: true, message: 'Document ID is required' },
+        { error: true, message: 'Request body must be valid JSON' },
         { status: 400 }
       );
     }
 
+    const parsed = searchRequestSchema.safeParse(body);
+    if (!parsed.success) {
+      return NextResponse.json(
+        {
+          error: true,
+          message: 'Invalid search request',
+          fieldErrors: parsed.error.flatten().fieldErrors
+        },
+        { status: 422 }
+      );
+    }
+
+    const params = parsed.data;
     let result;
 
-    if (type === 'single-word' && word) {
+    if (params.type === 'single-word') {
       result = await searchSingleWordTool.execute({
-        documentId,
-        word,
-        caseSensitive
+        documentId: params.documentId,
+        word: params.word,
+        caseSensitive: params.caseSensitive
       });
-    } else if (type === 'multiple-words' && searchTerms) {
+    } else {
       result = await searchDocumentTool.execute({
-        documentId,
-        searchTerms,
-        caseSensitive
+        documentId: params.documentId,
+        searchTerms: params.searchTerms,
+        caseSensitive: params.caseSensitive
       });
-    } else {
-      return NextResponse.json(
-        { error: true, message: 'Invalid search type or missing parameters' },
-        { status: 400 }
-      );
     }
 
     return NextResponse.json(result);
@@ -42,4 +71,4 @@ export async function POST(request: NextRequest) {
       { status: 500 }
     );
   }
-}
\ No newline at end of file
+}
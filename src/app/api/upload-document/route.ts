@@ -1,18 +1,107 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { uploadDocumentTool } from '@/lib/tools/document-search-tools';
+import { ALLOWED_UPLOAD_EXTENSIONS } from '@/lib/utils/upload-config';
+
+const DEFAULT_MAX_UPLOAD_BYTES = 10 * 1024 * 1024;
+
+// Maximum request body size (overridable via environment)
+function parseMaxUploadBytes(value: string | undefined): number {
+  if (value === undefined || value.trim() === '') return DEFAULT_MAX_UPLOAD_BYTES;
+
+  const parsed = Number(value);
+  if (!/^\d+$/.test(value.trim()) || !Number.isSafeInteger(parsed) || parsed <= 0) {
+    throw new Error(`Invalid MAX_UPLOAD_BYTES "${value}": must be a positive integer number of bytes`);
+  }
+  return parsed;
+}
+
+const MAX_UPLOAD_BYTES = parseMaxUploadBytes(process.env.MAX_UPLOAD_BYTES);
+
+class PayloadTooLargeError extends Error {}
+
+// Read the body with a running byte count so oversized uploads are rejected
+// before they are fully buffered, whether or not Content-Length is sent
+async function readBodyWithLimit(request: NextRequest, limit: number): Promise<string> {
+  if (!request.body) return '';
+
+  const reader = request.body.getReader();
+  const chunks: Uint8Array[] = [];
+  let received = 0;
+
+  while (true) {
+    const { done, value } = await reader.read();
+    if (done) break;
+
+    received += value.byteLength;
+    if (received > limit) {
+      await reader.cancel();
+      throw new PayloadTooLargeError();
+    }
+    chunks.push(value);
+  }
+
+  const buffer = new Uint8Array(received);
+  let offset = 0;
+  for (const chunk of chunks) {
+    buffer.set(chunk, offset);
+    offset += chunk.byteLength;
+  }
+  return new TextDecoder().decode(buffer);
+}
 
 export async function POST(request: NextRequest) {
   try {
-    const body = await request.json();
-    const { content, filename, id } = body;
+    const contentType = request.headers.get('content-type') || '';
+    if (!contentType.toLowerCase().startsWith('application/json')) {
+      return NextResponse.json(
+        { error: true, message: 'Unsupported content type, expected application/json' },
+        { status: 415 }
+      );
+    }
 
-    if (!content || !filename) {
+    let body;
+    try {
+      body = JSON.parse(await readBodyWithLimit(request, MAX_UPLOAD_BYTES));
+    } catch (error) {
+      if (error instanceof PayloadTooLargeError) {
+        return NextResponse.json(
+          { error: true, message: `Upload exceeds maximum size of ${MAX_UPLOAD_BYTES} bytes` },
+          { status: 413 }
+        );
+      }
       return NextResponse.json(
-        { error: true, message: 'Content and filename are required' },
+        { error: true, message: 'Request body must be valid JSON' },
         { status: 400 }
       );
     }
 
+    const parsed = uploadDocumentTool.parameters.safeParse(body);
+    if (!parsed.success) {
+      return NextResponse.json(
+        {
+          error: true,
+          message: 'Invalid upload request',
+          fieldErrors: parsed.error.flatten().fieldErrors
+        },
+        { status: 422 }
+      );
+    }
+
+    const { content, filename, id } = parsed.data;
+
+    const extension = filename.includes('.')
+      ? filename.slice(filename.lastIndexOf('.')).toLowerCase()
+      : '';
+    if (!ALLOWED_UPLOAD_EXTENSIONS.includes(extension)) {
+      return NextResponse.json(
+        {
+          error: true,
+          message: `File type "${extension || 'none'}" is not allowed. Allowed: ${ALLOWED_UPLOAD_EXTENSIONS.join(', ')}`
+        },
+        { status: 415 }
+      );
+    }
+
     const result = await uploadDocumentTool.execute({
       content,
       filename,
@@ -27,4 +116,4 @@ export async function POST(request: NextRequest) {
       { status: 500 }
     );
   }
-}
\ No newline at end of file
+}
@@ -2,6 +2,7 @@
 
 import React, { useState, useCallback } from 'react';
 import { Upload, Search, Users, Clock, FileText, Zap, BarChart3 } from 'lucide-react';
+import { ALLOWED_UPLOAD_EXTENSIONS } from '@/lib/utils/upload-config';
 
 interface SearchResult {
   type: 'single' | 'parallel';
@@ -142,7 +143,7 @@ export default function DocumentSearchInterface() {
           <div className="border-2 border-dashed border-gray-600 rounded-lg p-8 text-center">
             <input
               type="file"
-              accept=".txt,.pdf,.doc,.docx"
+              accept={ALLOWED_UPLOAD_EXTENSIONS.join(',')}
               onChange={handleFileUpload}
               disabled={isUploading}
               className="hidden"
@@ -9,8 +9,8 @@ export const uploadDocumentTool: Tool = {
   name: 'upload_document',
   description: 'Upload a document for searching',
   parameters: z.object({
-    content: z.string().describe('The full text content of the document'),
-    filename: z.string().describe('Name of the document'),
+    content: z.string().min(1, 'Content is required').describe('The full text content of the document'),
+    filename: z.string().min(1, 'Filename is required').describe('Name of the document'),
     id: z.string().optional().describe('Document ID (auto-generated if not provided)'),
   }),
   execute: async (params) => {
@@ -36,7 +36,7 @@ export const searchDocumentTool: Tool = {
   name: 'search_document',
   description: 'Search for specific words or phrases in an uploaded document',
   parameters: z.object({
-    documentId: z.string().describe('ID of the document to search'),
+    documentId: z.string().min(1, 'Document ID is required').describe('ID of the document to search'),
     searchTerms: z.array(z.string()).describe('Words or phrases to search for'),
     caseSensitive: z.boolean().default(false).describe('Whether the search is case sensitive'),
   }),
@@ -85,7 +85,7 @@ export const searchSingleWordTool: Tool = {
   name: 'search_single_word',
   description: 'Search for a single word in a document (optimized for parallel processing)',
   parameters: z.object({
-    documentId: z.string().describe('ID of the document to search'),
+    documentId: z.string().min(1, 'Document ID is required').describe('ID of the document to search'),
     word: z.string().describe('Single word to search for'),
     caseSensitive: z.boolean().default(false).describe('Whether the search is case sensitive'),
   }),
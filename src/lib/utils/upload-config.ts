@@ -0,0 +1,7 @@
+// Upload configuration shared by the upload API route and the upload UI.
+// NEXT_PUBLIC_ so the file picker sees the same allowlist the server enforces.
+
+export const ALLOWED_UPLOAD_EXTENSIONS = (process.env.NEXT_PUBLIC_ALLOWED_UPLOAD_EXTENSIONS || '.txt,.md,.csv,.json,.html,.xml,.log')
+  .split(',')
+  .map(ext => ext.trim().toLowerCase())
+  .filter(Boolean);